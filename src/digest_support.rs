@@ -0,0 +1,103 @@
+//! Implementations of the RustCrypto `digest` traits, gated behind the
+//! `digest` feature.
+//!
+//! Each of the `State*` hasher states implements `Update`, `OutputSizeUser`,
+//! `FixedOutput` and `Reset`. `HashMarker` is not implemented, so the blanket
+//! `Digest` impl does not apply to these types. `finalize_into` writes the
+//! same canonical big-endian bytes
+//! as `Hash32`/`Hash64`/`Hash128::bytes()`, so the resulting digests are
+//! stable and portable.
+
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{U4, U8, U16};
+use digest::{FixedOutput, OutputSizeUser, Reset, Update};
+
+use {Hash32, Hash64, Hash128, State32, State3_128, State3_64, State64};
+
+impl Update for State32 {
+    fn update(&mut self, data: &[u8]) {
+        State32::update(self, data)
+    }
+}
+
+impl OutputSizeUser for State32 {
+    type OutputSize = U4;
+}
+
+impl FixedOutput for State32 {
+    fn finalize_into(self, out: &mut GenericArray<u8, U4>) {
+        out.copy_from_slice(Hash32::from(self.finish()).bytes());
+    }
+}
+
+impl Reset for State32 {
+    fn reset(&mut self) {
+        State32::reset(self, 0)
+    }
+}
+
+impl Update for State64 {
+    fn update(&mut self, data: &[u8]) {
+        State64::update(self, data)
+    }
+}
+
+impl OutputSizeUser for State64 {
+    type OutputSize = U8;
+}
+
+impl FixedOutput for State64 {
+    fn finalize_into(self, out: &mut GenericArray<u8, U8>) {
+        out.copy_from_slice(Hash64::from(self.finish()).bytes());
+    }
+}
+
+impl Reset for State64 {
+    fn reset(&mut self) {
+        State64::reset(self, 0)
+    }
+}
+
+impl Update for State3_64 {
+    fn update(&mut self, data: &[u8]) {
+        State3_64::update(self, data)
+    }
+}
+
+impl OutputSizeUser for State3_64 {
+    type OutputSize = U8;
+}
+
+impl FixedOutput for State3_64 {
+    fn finalize_into(self, out: &mut GenericArray<u8, U8>) {
+        out.copy_from_slice(Hash64::from(self.finish()).bytes());
+    }
+}
+
+impl Reset for State3_64 {
+    fn reset(&mut self) {
+        State3_64::reset(self, 0)
+    }
+}
+
+impl Update for State3_128 {
+    fn update(&mut self, data: &[u8]) {
+        State3_128::update(self, data)
+    }
+}
+
+impl OutputSizeUser for State3_128 {
+    type OutputSize = U16;
+}
+
+impl FixedOutput for State3_128 {
+    fn finalize_into(self, out: &mut GenericArray<u8, U16>) {
+        out.copy_from_slice(Hash128::from(self.finish()).bytes());
+    }
+}
+
+impl Reset for State3_128 {
+    fn reset(&mut self) {
+        State3_128::reset(self, 0)
+    }
+}
@@ -27,13 +27,117 @@
 
 extern crate libc;
 extern crate xxhash_sys;
+#[cfg(feature = "digest")]
+extern crate digest;
+#[cfg(feature = "serde")]
+extern crate serde;
 
+#[cfg(feature = "digest")]
+mod digest_support;
+#[cfg(feature = "serde")]
+mod serde_support;
+
+use std::error;
 use std::fmt;
-use std::hash::Hasher;
+use std::fs::File;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, Read};
 use std::mem;
+use std::path::Path;
+use std::str::FromStr;
 
 use libc::{size_t, c_void};
 
+/// Size, in bytes, of the chunks read from a `Read` implementation by the
+/// `update_reader` methods.
+const READER_BUF_SIZE: usize = 64 * 1024;
+
+/// Streams `r` through `update` in fixed-size chunks, without buffering the
+/// whole input in memory.
+fn stream_reader<R, F>(r: &mut R, mut update: F) -> io::Result<()>
+    where R: Read,
+          F: FnMut(&[u8])
+{
+    let mut buf = [0u8; READER_BUF_SIZE];
+    loop {
+        let n = match r.read(&mut buf) {
+            Ok(n) => n,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if n == 0 {
+            return Ok(());
+        }
+        update(&buf[..n]);
+    }
+}
+
+/// Error returned when parsing a canonical hash from a hex string fails.
+#[derive(Debug)]
+pub struct ParseHashError {
+    kind: ParseHashErrorKind,
+}
+
+#[derive(Debug)]
+enum ParseHashErrorKind {
+    BadLength,
+    BadHex,
+}
+
+impl ParseHashError {
+    fn description(&self) -> &str {
+        match self.kind {
+            ParseHashErrorKind::BadLength => "hex string is the wrong length for this hash",
+            ParseHashErrorKind::BadHex => "hex string contains a non-hex digit",
+        }
+    }
+}
+
+impl fmt::Display for ParseHashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl error::Error for ParseHashError {
+    fn description(&self) -> &str {
+        ParseHashError::description(self)
+    }
+}
+
+fn write_hex(bytes: &[u8], f: &mut fmt::Formatter, upper: bool) -> fmt::Result {
+    for b in bytes {
+        if upper {
+            write!(f, "{:02X}", b)?;
+        } else {
+            write!(f, "{:02x}", b)?;
+        }
+    }
+    Ok(())
+}
+
+fn hex_digit(b: u8) -> Result<u8, ParseHashError> {
+    match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        b'a'..=b'f' => Ok(b - b'a' + 10),
+        b'A'..=b'F' => Ok(b - b'A' + 10),
+        _ => Err(ParseHashError { kind: ParseHashErrorKind::BadHex }),
+    }
+}
+
+fn parse_hex(s: &str, out: &mut [u8]) -> Result<(), ParseHashError> {
+    if s.len() != out.len() * 2 || !s.is_ascii() {
+        return Err(ParseHashError { kind: ParseHashErrorKind::BadLength });
+    }
+    let s = s.as_bytes();
+    for (i, byte) in out.iter_mut().enumerate() {
+        let hi = hex_digit(s[i * 2])?;
+        let lo = hex_digit(s[i * 2 + 1])?;
+        *byte = (hi << 4) | lo;
+    }
+    Ok(())
+}
+
 /// Representation of the intermediate state of a 32-bit xxHash instance.
 ///
 /// This structure can be used to generate a 32-bit hash of a block of bytes.
@@ -48,6 +152,78 @@ pub struct State64 {
     inner: xxhash_sys::XXH64_stateBody_t,
 }
 
+/// Representation of the intermediate state of an XXH3 64-bit hashing
+/// instance.
+///
+/// This structure can be used to generate a 64-bit hash of a block of bytes
+/// using the XXH3 algorithm, which is considerably faster than XXH32/XXH64
+/// on modern hardware and can optionally be keyed with a seed or a custom
+/// secret.
+pub struct State3_64 {
+    inner: xxhash_sys::XXH3_stateBody_t,
+}
+
+/// Representation of the intermediate state of an XXH3 128-bit hashing
+/// instance.
+///
+/// This structure can be used to generate a 128-bit hash of a block of bytes
+/// using the XXH3 algorithm, and shares its internal layout with
+/// `State3_64`.
+pub struct State3_128 {
+    inner: xxhash_sys::XXH3_stateBody_t,
+}
+
+/// Minimum size, in bytes, of a custom secret passed to the XXH3 secret
+/// variants (`hash3_64_with_secret`, `State3_64::reset_with_secret`).
+pub const SECRET_SIZE_MIN: usize = xxhash_sys::XXH3_SECRET_SIZE_MIN;
+
+/// Size, in bytes, of the secret produced by `generate_secret`.
+pub const SECRET_DEFAULT_SIZE: usize = xxhash_sys::XXH3_SECRET_DEFAULT_SIZE;
+
+/// Canonical representation of a 128-bit hash.
+///
+/// This structure provides a conversion from a 128-bit hash value to a list
+/// of bytes in a canonical format. Note that the bytes returned are not
+/// necessarily hex.
+pub struct Hash128 {
+    inner: xxhash_sys::XXH128_canonical_t,
+}
+
+/// A `BuildHasher` that always constructs 32-bit xxHash hashers seeded with
+/// a fixed value.
+///
+/// This mirrors the split between a hashing algorithm (`State32`) and the
+/// factory that produces it, the same way `std::collections::hash_map`
+/// separates a `Hasher` from its `BuildHasher`.
+#[derive(Clone, Copy, Debug)]
+pub struct Xxh32BuildHasher {
+    seed: u32,
+}
+
+/// A `BuildHasher` that always constructs 64-bit xxHash hashers seeded with
+/// a fixed value.
+#[derive(Clone, Copy, Debug)]
+pub struct Xxh64BuildHasher {
+    seed: u64,
+}
+
+/// A `BuildHasher` that seeds its 64-bit xxHash hashers from a
+/// process-random source.
+///
+/// This allows `xxhash2::State64` to be used as the hasher of a `HashMap`
+/// without picking a fixed, predictable seed:
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// let mut map: HashMap<u32, u32, xxhash2::RandomState> = HashMap::default();
+/// map.insert(1, 2);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RandomState {
+    seed: u64,
+}
+
 /// Canonical representation of a 32-bit hash.
 ///
 /// This structure provides a conversion from a 32-bit hash value to a list of
@@ -102,6 +278,154 @@ pub fn hash64(data: &[u8], seed: u64) -> u64 {
     }
 }
 
+/// Hash the contents of a `Read` implementation with a given seed, returning
+/// the 64-bit hash.
+///
+/// This streams the reader through a `State64` in fixed-size chunks, so it
+/// does not need to buffer the whole input in memory.
+///
+/// # Examples
+///
+/// ```
+/// let hash = xxhash2::hash64_reader(&mut &[1, 2, 3, 4][..], 0).unwrap();
+/// assert_eq!(hash, 6063570110359613137);
+/// ```
+pub fn hash64_reader<R: Read>(r: &mut R, seed: u64) -> io::Result<u64> {
+    let mut state = State64::new();
+    state.reset(seed);
+    state.update_reader(r)?;
+    Ok(state.finish())
+}
+
+/// Hash the contents of a file with a given seed, returning the 64-bit hash.
+///
+/// This streams the file through a `State64` in fixed-size chunks, so it
+/// does not need to buffer the whole file in memory.
+pub fn hash64_file<P: AsRef<Path>>(path: P, seed: u64) -> io::Result<u64> {
+    let mut file = File::open(path)?;
+    hash64_reader(&mut file, seed)
+}
+
+/// Hash a block of bytes with a given seed using the XXH3 algorithm,
+/// returning the 64-bit hash.
+///
+/// This function, optimized for speed, will hash an entire block all at once
+/// and return the hash value.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(xxhash2::hash3_64(&[], 0), 0x2d06800538d394c2);
+/// ```
+pub fn hash3_64(data: &[u8], seed: u64) -> u64 {
+    unsafe {
+        xxhash_sys::XXH3_64bits_withSeed(data.as_ptr() as *const c_void,
+                                         data.len() as size_t,
+                                         seed)
+    }
+}
+
+/// Hash a block of bytes using the XXH3 algorithm with a custom secret,
+/// returning the 64-bit hash.
+///
+/// The `secret` must be at least `SECRET_SIZE_MIN` bytes long; shorter
+/// secrets will cause a panic. A suitable secret can be produced with
+/// `generate_secret`.
+///
+/// # Examples
+///
+/// ```
+/// let secret = xxhash2::generate_secret(&[1, 2, 3, 4]);
+/// xxhash2::hash3_64_with_secret(&[1, 2, 3, 4], &secret);
+/// ```
+pub fn hash3_64_with_secret(data: &[u8], secret: &[u8]) -> u64 {
+    assert!(secret.len() >= SECRET_SIZE_MIN);
+    unsafe {
+        xxhash_sys::XXH3_64bits_withSecret(data.as_ptr() as *const c_void,
+                                           data.len() as size_t,
+                                           secret.as_ptr() as *const c_void,
+                                           secret.len() as size_t)
+    }
+}
+
+/// Generates an XXH3 secret from a custom seed.
+///
+/// The returned buffer is `SECRET_DEFAULT_SIZE` bytes long and can be passed
+/// to `hash3_64_with_secret` or `State3_64::reset_with_secret`.
+///
+/// # Examples
+///
+/// ```
+/// let secret = xxhash2::generate_secret(&[1, 2, 3, 4]);
+/// assert_eq!(secret.len(), xxhash2::SECRET_DEFAULT_SIZE);
+/// ```
+pub fn generate_secret(custom_seed: &[u8]) -> Vec<u8> {
+    let mut secret = vec![0u8; SECRET_DEFAULT_SIZE];
+    let r = unsafe {
+        xxhash_sys::XXH3_generateSecret(secret.as_mut_ptr() as *mut c_void,
+                                        secret.len() as size_t,
+                                        custom_seed.as_ptr() as *const c_void,
+                                        custom_seed.len() as size_t)
+    };
+    assert_eq!(r, xxhash_sys::XXH_OK);
+    secret
+}
+
+/// Hash a block of bytes with a given seed using the XXH3 algorithm,
+/// returning the 128-bit hash.
+///
+/// This function, optimized for speed, will hash an entire block all at once
+/// and return the hash value.
+///
+/// # Examples
+///
+/// ```
+/// xxhash2::hash128(&[1, 2, 3, 4], 0);
+/// ```
+pub fn hash128(data: &[u8], seed: u64) -> u128 {
+    let hash = unsafe {
+        xxhash_sys::XXH3_128bits_withSeed(data.as_ptr() as *const c_void,
+                                          data.len() as size_t,
+                                          seed)
+    };
+    combine128(hash)
+}
+
+/// Hash a block of bytes using the XXH3 algorithm with a custom secret,
+/// returning the 128-bit hash.
+///
+/// The `secret` must be at least `SECRET_SIZE_MIN` bytes long; shorter
+/// secrets will cause a panic. A suitable secret can be produced with
+/// `generate_secret`.
+///
+/// # Examples
+///
+/// ```
+/// let secret = xxhash2::generate_secret(&[1, 2, 3, 4]);
+/// xxhash2::hash128_with_secret(&[1, 2, 3, 4], &secret);
+/// ```
+pub fn hash128_with_secret(data: &[u8], secret: &[u8]) -> u128 {
+    assert!(secret.len() >= SECRET_SIZE_MIN);
+    let hash = unsafe {
+        xxhash_sys::XXH3_128bits_withSecret(data.as_ptr() as *const c_void,
+                                            data.len() as size_t,
+                                            secret.as_ptr() as *const c_void,
+                                            secret.len() as size_t)
+    };
+    combine128(hash)
+}
+
+fn combine128(hash: xxhash_sys::XXH128_hash_t) -> u128 {
+    ((hash.high64 as u128) << 64) | hash.low64 as u128
+}
+
+fn split128(val: u128) -> xxhash_sys::XXH128_hash_t {
+    xxhash_sys::XXH128_hash_t {
+        low64: val as u64,
+        high64: (val >> 64) as u64,
+    }
+}
+
 impl State32 {
     /// Creates a new blank instance of the 32-bit xxHash state.
     ///
@@ -140,6 +464,22 @@ impl State32 {
         assert_eq!(r, xxhash_sys::XXH_OK);
     }
 
+    /// Hashes the contents of a `Read` implementation by streaming it through
+    /// this hasher in fixed-size chunks, without buffering the whole input in
+    /// memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State32::new();
+    /// state.reset(0);
+    /// state.update_reader(&mut &[1, 2, 3, 4][..]).unwrap();
+    /// assert_eq!(state.finish(), 4271296924);
+    /// ```
+    pub fn update_reader<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        stream_reader(r, |data| self.update(data))
+    }
+
     /// Reset the internal state of this hasher with a given seed.
     ///
     /// This is useful to reuse a hasher or to persist the state across
@@ -241,6 +581,22 @@ impl State64 {
         assert_eq!(r, xxhash_sys::XXH_OK);
     }
 
+    /// Hashes the contents of a `Read` implementation by streaming it through
+    /// this hasher in fixed-size chunks, without buffering the whole input in
+    /// memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State64::new();
+    /// state.reset(0);
+    /// state.update_reader(&mut &[1, 2, 3, 4][..]).unwrap();
+    /// assert_eq!(state.finish(), 6063570110359613137);
+    /// ```
+    pub fn update_reader<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        stream_reader(r, |data| self.update(data))
+    }
+
     /// Reset the internal state of this hasher with a given seed.
     ///
     /// This is useful to reuse a hasher or to persist the state across
@@ -304,6 +660,374 @@ impl Default for State64 {
     }
 }
 
+impl State3_64 {
+    /// Creates a new blank instance of the XXH3 64-bit hashing state.
+    ///
+    /// This state can then be used to hash a list of bytes and acquire the
+    /// result via the `finish` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let state = xxhash2::State3_64::new();
+    /// ```
+    pub fn new() -> State3_64 {
+        unsafe {
+            State3_64 { inner: mem::zeroed() }
+        }
+    }
+
+    /// Input a block of bytes into this hasher.
+    ///
+    /// This function will update the internal state of this hasher with a new
+    /// list of bytes to feed in. To get the hash value of the block (and all
+    /// previous bytes), call the `finish` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State3_64::new();
+    /// state.update(&[1, 2, 3, 4]);
+    /// ```
+    pub fn update(&mut self, data: &[u8]) {
+        let r = unsafe {
+            xxhash_sys::XXH3_64bits_update(self.inner(),
+                                           data.as_ptr() as *const c_void,
+                                           data.len() as size_t)
+        };
+        assert_eq!(r, xxhash_sys::XXH_OK);
+    }
+
+    /// Hashes the contents of a `Read` implementation by streaming it through
+    /// this hasher in fixed-size chunks, without buffering the whole input in
+    /// memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State3_64::new();
+    /// state.reset(0);
+    /// state.update_reader(&mut &[1, 2, 3, 4][..]).unwrap();
+    /// ```
+    pub fn update_reader<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        stream_reader(r, |data| self.update(data))
+    }
+
+    /// Reset the internal state of this hasher with a given seed.
+    ///
+    /// This is useful to reuse a hasher or to persist the state across
+    /// multiple runs of a hasher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State3_64::new();
+    /// state.reset(0);
+    /// state.update(&[1, 2, 3, 4]);
+    /// ```
+    pub fn reset(&mut self, seed: u64) {
+        let r = unsafe {
+            xxhash_sys::XXH3_64bits_reset_withSeed(self.inner(), seed)
+        };
+        assert_eq!(r, xxhash_sys::XXH_OK);
+    }
+
+    /// Reset the internal state of this hasher with a custom secret.
+    ///
+    /// The `secret` must be at least `SECRET_SIZE_MIN` bytes long; shorter
+    /// secrets will cause a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let secret = xxhash2::generate_secret(&[1, 2, 3, 4]);
+    /// let mut state = xxhash2::State3_64::new();
+    /// state.reset_with_secret(&secret);
+    /// state.update(&[1, 2, 3, 4]);
+    /// ```
+    pub fn reset_with_secret(&mut self, secret: &[u8]) {
+        assert!(secret.len() >= SECRET_SIZE_MIN);
+        let r = unsafe {
+            xxhash_sys::XXH3_64bits_reset_withSecret(self.inner(),
+                                                      secret.as_ptr() as *const c_void,
+                                                      secret.len() as size_t)
+        };
+        assert_eq!(r, xxhash_sys::XXH_OK);
+    }
+
+    /// Computes the final hash result of all bytes that have been input to
+    /// this hasher.
+    ///
+    /// Returns the 64-bit checksum of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State3_64::new();
+    /// state.reset(0);
+    /// state.update(&[1, 2, 3, 4]);
+    /// state.finish();
+    /// ```
+    pub fn finish(&self) -> u64 {
+        unsafe {
+            xxhash_sys::XXH3_64bits_digest(&self.inner as *const _ as *const _)
+        }
+    }
+
+    fn inner(&mut self) -> *mut xxhash_sys::XXH3_state_t {
+        &mut self.inner as *mut _ as *mut _
+    }
+}
+
+impl Hasher for State3_64 {
+    fn write(&mut self, data: &[u8]) {
+        self.update(data)
+    }
+
+    fn finish(&self) -> u64 {
+        self.finish()
+    }
+}
+
+impl Clone for State3_64 {
+    fn clone(&self) -> State3_64 {
+        State3_64 { inner: self.inner }
+    }
+}
+
+impl Default for State3_64 {
+    fn default() -> State3_64 {
+        State3_64::new()
+    }
+}
+
+impl State3_128 {
+    /// Creates a new blank instance of the XXH3 128-bit hashing state.
+    ///
+    /// This state can then be used to hash a list of bytes and acquire the
+    /// result via the `finish` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let state = xxhash2::State3_128::new();
+    /// ```
+    pub fn new() -> State3_128 {
+        unsafe {
+            State3_128 { inner: mem::zeroed() }
+        }
+    }
+
+    /// Input a block of bytes into this hasher.
+    ///
+    /// This function will update the internal state of this hasher with a new
+    /// list of bytes to feed in. To get the hash value of the block (and all
+    /// previous bytes), call the `finish` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State3_128::new();
+    /// state.update(&[1, 2, 3, 4]);
+    /// ```
+    pub fn update(&mut self, data: &[u8]) {
+        let r = unsafe {
+            xxhash_sys::XXH3_128bits_update(self.inner(),
+                                            data.as_ptr() as *const c_void,
+                                            data.len() as size_t)
+        };
+        assert_eq!(r, xxhash_sys::XXH_OK);
+    }
+
+    /// Hashes the contents of a `Read` implementation by streaming it through
+    /// this hasher in fixed-size chunks, without buffering the whole input in
+    /// memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State3_128::new();
+    /// state.reset(0);
+    /// state.update_reader(&mut &[1, 2, 3, 4][..]).unwrap();
+    /// ```
+    pub fn update_reader<R: Read>(&mut self, r: &mut R) -> io::Result<()> {
+        stream_reader(r, |data| self.update(data))
+    }
+
+    /// Reset the internal state of this hasher with a given seed.
+    ///
+    /// This is useful to reuse a hasher or to persist the state across
+    /// multiple runs of a hasher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State3_128::new();
+    /// state.reset(0);
+    /// state.update(&[1, 2, 3, 4]);
+    /// ```
+    pub fn reset(&mut self, seed: u64) {
+        let r = unsafe {
+            xxhash_sys::XXH3_128bits_reset_withSeed(self.inner(), seed)
+        };
+        assert_eq!(r, xxhash_sys::XXH_OK);
+    }
+
+    /// Reset the internal state of this hasher with a custom secret.
+    ///
+    /// The `secret` must be at least `SECRET_SIZE_MIN` bytes long; shorter
+    /// secrets will cause a panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let secret = xxhash2::generate_secret(&[1, 2, 3, 4]);
+    /// let mut state = xxhash2::State3_128::new();
+    /// state.reset_with_secret(&secret);
+    /// state.update(&[1, 2, 3, 4]);
+    /// ```
+    pub fn reset_with_secret(&mut self, secret: &[u8]) {
+        assert!(secret.len() >= SECRET_SIZE_MIN);
+        let r = unsafe {
+            xxhash_sys::XXH3_128bits_reset_withSecret(self.inner(),
+                                                       secret.as_ptr() as *const c_void,
+                                                       secret.len() as size_t)
+        };
+        assert_eq!(r, xxhash_sys::XXH_OK);
+    }
+
+    /// Computes the final hash result of all bytes that have been input to
+    /// this hasher.
+    ///
+    /// Returns the 128-bit checksum of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut state = xxhash2::State3_128::new();
+    /// state.reset(0);
+    /// state.update(&[1, 2, 3, 4]);
+    /// state.finish();
+    /// ```
+    pub fn finish(&self) -> u128 {
+        let hash = unsafe {
+            xxhash_sys::XXH3_128bits_digest(&self.inner as *const _ as *const _)
+        };
+        combine128(hash)
+    }
+
+    fn inner(&mut self) -> *mut xxhash_sys::XXH3_state_t {
+        &mut self.inner as *mut _ as *mut _
+    }
+}
+
+impl Clone for State3_128 {
+    fn clone(&self) -> State3_128 {
+        State3_128 { inner: self.inner }
+    }
+}
+
+impl Default for State3_128 {
+    fn default() -> State3_128 {
+        State3_128::new()
+    }
+}
+
+impl Xxh32BuildHasher {
+    /// Creates a new build hasher that seeds every `State32` it constructs
+    /// with the given seed.
+    pub fn new(seed: u32) -> Xxh32BuildHasher {
+        Xxh32BuildHasher { seed }
+    }
+}
+
+impl BuildHasher for Xxh32BuildHasher {
+    type Hasher = State32;
+
+    fn build_hasher(&self) -> State32 {
+        let mut state = State32::new();
+        state.reset(self.seed);
+        state
+    }
+}
+
+impl Default for Xxh32BuildHasher {
+    fn default() -> Xxh32BuildHasher {
+        Xxh32BuildHasher::new(0)
+    }
+}
+
+impl Xxh64BuildHasher {
+    /// Creates a new build hasher that seeds every `State64` it constructs
+    /// with the given seed.
+    pub fn new(seed: u64) -> Xxh64BuildHasher {
+        Xxh64BuildHasher { seed }
+    }
+}
+
+impl BuildHasher for Xxh64BuildHasher {
+    type Hasher = State64;
+
+    fn build_hasher(&self) -> State64 {
+        let mut state = State64::new();
+        state.reset(self.seed);
+        state
+    }
+}
+
+impl Default for Xxh64BuildHasher {
+    fn default() -> Xxh64BuildHasher {
+        Xxh64BuildHasher::new(0)
+    }
+}
+
+impl RandomState {
+    /// Creates a new `RandomState` seeded from a process-random source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = xxhash2::RandomState::new();
+    /// ```
+    pub fn new() -> RandomState {
+        RandomState { seed: random_seed() }
+    }
+
+    /// Creates a new `RandomState` with a fixed seed, for reproducible
+    /// hashing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = xxhash2::RandomState::with_seed(0);
+    /// ```
+    pub fn with_seed(seed: u64) -> RandomState {
+        RandomState { seed }
+    }
+}
+
+impl BuildHasher for RandomState {
+    type Hasher = State64;
+
+    fn build_hasher(&self) -> State64 {
+        let mut state = State64::new();
+        state.reset(self.seed);
+        state
+    }
+}
+
+impl Default for RandomState {
+    fn default() -> RandomState {
+        RandomState::new()
+    }
+}
+
+fn random_seed() -> u64 {
+    use std::collections::hash_map::RandomState as StdRandomState;
+
+    StdRandomState::new().build_hasher().finish()
+}
+
 impl Hash32 {
     /// Returns the underlying hash as a list of bytes in a canonical
     /// representation.
@@ -350,6 +1074,37 @@ impl fmt::Debug for Hash32 {
     }
 }
 
+impl fmt::Display for Hash32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Hash32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(self.bytes(), f, false)
+    }
+}
+
+impl fmt::UpperHex for Hash32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(self.bytes(), f, true)
+    }
+}
+
+impl FromStr for Hash32 {
+    type Err = ParseHashError;
+
+    /// Parses the fixed-width hex string produced by `Display`/`LowerHex`
+    /// back into a canonical 32-bit hash, erroring on the wrong length or
+    /// non-hex characters.
+    fn from_str(s: &str) -> Result<Hash32, ParseHashError> {
+        let mut bytes = [0u8; 4];
+        parse_hex(s, &mut bytes)?;
+        Ok(Hash32::from(bytes))
+    }
+}
+
 impl Hash64 {
     /// Returns the underlying hash as a list of bytes in a canonical
     /// representation.
@@ -396,10 +1151,131 @@ impl fmt::Debug for Hash64 {
     }
 }
 
+impl fmt::Display for Hash64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Hash64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(self.bytes(), f, false)
+    }
+}
+
+impl fmt::UpperHex for Hash64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(self.bytes(), f, true)
+    }
+}
+
+impl FromStr for Hash64 {
+    type Err = ParseHashError;
+
+    /// Parses the fixed-width hex string produced by `Display`/`LowerHex`
+    /// back into a canonical 64-bit hash, erroring on the wrong length or
+    /// non-hex characters.
+    fn from_str(s: &str) -> Result<Hash64, ParseHashError> {
+        let mut bytes = [0u8; 8];
+        parse_hex(s, &mut bytes)?;
+        Ok(Hash64::from(bytes))
+    }
+}
+
+impl Hash128 {
+    /// Returns the underlying hash as a list of bytes in a canonical
+    /// representation.
+    pub fn bytes(&self) -> &[u8; 16] {
+        &self.inner.digest
+    }
+
+    /// Returns this canonical hash value as a 128-bit integer.
+    ///
+    /// Converts from the underlying list of bytes to an integer.
+    pub fn value(&self) -> u128 {
+        unsafe {
+            combine128(xxhash_sys::XXH128_hashFromCanonical(&self.inner))
+        }
+    }
+}
+
+impl From<u128> for Hash128 {
+    /// Creates a new canonical representation of a 128-bit hash value.
+    ///
+    /// The returned value can be viewed as a list of bytes.
+    fn from(val: u128) -> Hash128 {
+        unsafe {
+            let mut ret = Hash128 { inner: mem::zeroed() };
+            xxhash_sys::XXH128_canonicalFromHash(&mut ret.inner, split128(val));
+            return ret
+        }
+    }
+}
+
+impl From<[u8; 16]> for Hash128 {
+    /// Creates a new canonical representation of a hash from the 16-byte
+    /// canonical representation.
+    ///
+    /// The returned value can be viewed as a `u128`.
+    fn from(val: [u8; 16]) -> Hash128 {
+        Hash128 { inner: xxhash_sys::XXH128_canonical_t { digest: val } }
+    }
+}
+
+impl fmt::Debug for Hash128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.digest.fmt(f)
+    }
+}
+
+impl fmt::Display for Hash128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::LowerHex for Hash128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(self.bytes(), f, false)
+    }
+}
+
+impl fmt::UpperHex for Hash128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(self.bytes(), f, true)
+    }
+}
+
+impl FromStr for Hash128 {
+    type Err = ParseHashError;
+
+    /// Parses the fixed-width hex string produced by `Display`/`LowerHex`
+    /// back into a canonical 128-bit hash, erroring on the wrong length or
+    /// non-hex characters.
+    fn from_str(s: &str) -> Result<Hash128, ParseHashError> {
+        let mut bytes = [0u8; 16];
+        parse_hex(s, &mut bytes)?;
+        Ok(Hash128::from(bytes))
+    }
+}
+
+impl PartialEq for Hash128 {
+    fn eq(&self, other: &Hash128) -> bool {
+        unsafe {
+            xxhash_sys::XXH128_isEqual(xxhash_sys::XXH128_hashFromCanonical(&self.inner),
+                                       xxhash_sys::XXH128_hashFromCanonical(&other.inner)) != 0
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+
     fn test32(data: &[u8], seed: u32, expected: u32) {
         assert_eq!(hash32(data, seed), expected);
         let mut state = State32::new();
@@ -430,6 +1306,22 @@ mod tests {
         assert_eq!(state.finish(), expected);
     }
 
+    fn test3_64(data: &[u8], seed: u64) {
+        let oneshot = hash3_64(data, seed);
+
+        let mut state = State3_64::new();
+        state.reset(seed);
+        state.update(data);
+        assert_eq!(state.finish(), oneshot);
+        assert_eq!(state.finish(), oneshot);
+
+        state.reset(seed);
+        for i in 0..data.len() {
+            state.update(&data[i..i + 1]);
+        }
+        assert_eq!(state.finish(), oneshot);
+    }
+
     #[test]
     fn smoke() {
         test32(&[], 0, 46947589);
@@ -440,6 +1332,28 @@ mod tests {
         test64(&[1, 2, 3, 4], 0, 6063570110359613137);
     }
 
+    #[test]
+    fn smoke3_64() {
+        assert_eq!(hash3_64(&[], 0), 0x2d06800538d394c2);
+
+        test3_64(&[], 0);
+        test3_64(&[1], 0);
+        test3_64(&[1, 2, 3, 4], 0);
+        test3_64(&[1, 2, 3, 4], 123);
+    }
+
+    #[test]
+    fn secret() {
+        let secret = generate_secret(&[1, 2, 3, 4]);
+        assert_eq!(secret.len(), SECRET_DEFAULT_SIZE);
+
+        let oneshot = hash3_64_with_secret(&[1, 2, 3, 4], &secret);
+        let mut state = State3_64::new();
+        state.reset_with_secret(&secret);
+        state.update(&[1, 2, 3, 4]);
+        assert_eq!(state.finish(), oneshot);
+    }
+
     #[test]
     fn hash() {
         assert_eq!(Hash32::from(0).bytes(), &[0, 0, 0, 0]);
@@ -451,5 +1365,106 @@ mod tests {
         assert_eq!(Hash64::from(0x12345678).bytes(),
                    &[0, 0, 0, 0, 0x12, 0x34, 0x56, 0x78]);
         assert_eq!(Hash64::from(0x12345678).value(), 0x12345678);
+        assert_eq!(Hash128::from(0).bytes(),
+                   &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Hash128::from(0).value(), 0);
+        assert_eq!(Hash128::from(0x12345678).bytes(),
+                   &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(Hash128::from(0x12345678).value(), 0x12345678);
+        assert_eq!(Hash128::from(0), Hash128::from(0));
+    }
+
+    fn test3_128(data: &[u8], seed: u64) {
+        let oneshot = hash128(data, seed);
+
+        let mut state = State3_128::new();
+        state.reset(seed);
+        state.update(data);
+        assert_eq!(state.finish(), oneshot);
+        assert_eq!(state.finish(), oneshot);
+
+        state.reset(seed);
+        for i in 0..data.len() {
+            state.update(&data[i..i + 1]);
+        }
+        assert_eq!(state.finish(), oneshot);
+    }
+
+    #[test]
+    fn smoke128() {
+        test3_128(&[], 0);
+        test3_128(&[1], 0);
+        test3_128(&[1, 2, 3, 4], 0);
+        test3_128(&[1, 2, 3, 4], 123);
+    }
+
+    #[test]
+    fn build_hasher() {
+        use std::collections::HashMap;
+
+        let mut map: HashMap<u32, u32, Xxh32BuildHasher> = HashMap::default();
+        map.insert(1, 2);
+        assert_eq!(map.get(&1), Some(&2));
+
+        let mut map: HashMap<u32, u32, Xxh64BuildHasher> = HashMap::default();
+        map.insert(1, 2);
+        assert_eq!(map.get(&1), Some(&2));
+
+        let mut map: HashMap<u32, u32, RandomState> = HashMap::default();
+        map.insert(1, 2);
+        assert_eq!(map.get(&1), Some(&2));
+
+        let mut map: HashMap<u32, u32, RandomState> =
+            HashMap::with_hasher(RandomState::with_seed(0));
+        map.insert(1, 2);
+        assert_eq!(map.get(&1), Some(&2));
+    }
+
+    #[test]
+    fn reader() {
+        let data = &[1, 2, 3, 4][..];
+
+        let mut state = State64::new();
+        state.reset(0);
+        state.update_reader(&mut &*data).unwrap();
+        assert_eq!(state.finish(), hash64(data, 0));
+
+        assert_eq!(hash64_reader(&mut &*data, 0).unwrap(), hash64(data, 0));
+
+        let dir = env::temp_dir();
+        let path = dir.join("xxhash2-reader-test");
+        File::create(&path).unwrap().write_all(data).unwrap();
+        assert_eq!(hash64_file(&path, 0).unwrap(), hash64(data, 0));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn hex() {
+        let hash = Hash32::from(0x12345678);
+        assert_eq!(hash.to_string(), "12345678");
+        assert_eq!(format!("{:x}", hash), "12345678");
+        assert_eq!(format!("{:X}", hash), "12345678");
+        assert_eq!("12345678".parse::<Hash32>().unwrap().value(), 0x12345678);
+        assert!("1234567".parse::<Hash32>().is_err());
+        assert!("1234567g".parse::<Hash32>().is_err());
+
+        let hash = Hash64::from(0x123456789abcdef0);
+        assert_eq!(hash.to_string(), "123456789abcdef0");
+        assert_eq!("123456789abcdef0".parse::<Hash64>().unwrap().value(),
+                   0x123456789abcdef0);
+
+        let hash = Hash128::from(0x12345678);
+        assert_eq!(hash.to_string(), "00000000000000000000000012345678");
+    }
+
+    #[test]
+    fn secret128() {
+        let secret = generate_secret(&[1, 2, 3, 4]);
+        let oneshot = hash128_with_secret(&[1, 2, 3, 4], &secret);
+
+        let mut state = State3_128::new();
+        state.reset_with_secret(&secret);
+        state.update(&[1, 2, 3, 4]);
+        assert_eq!(state.finish(), oneshot);
     }
 }
@@ -0,0 +1,62 @@
+//! `serde` support for the canonical `Hash*` types, gated behind the
+//! `serde` feature.
+//!
+//! Human-readable formats (JSON, TOML, ...) serialize as the hex string
+//! produced by `Display`/`LowerHex`; binary formats serialize as the raw
+//! canonical bytes, written and read back through the same `serialize_bytes`/
+//! `deserialize_bytes` pair so the round trip agrees on the wire format.
+
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use {Hash128, Hash32, Hash64};
+
+macro_rules! impl_serde {
+    ($ty:ident, $visitor:ident, $len:expr) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(&self.to_string())
+                } else {
+                    serializer.serialize_bytes(self.bytes())
+                }
+            }
+        }
+
+        struct $visitor;
+
+        impl<'de> Visitor<'de> for $visitor {
+            type Value = $ty;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} bytes", $len)
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<$ty, E> {
+                if v.len() != $len {
+                    return Err(de::Error::invalid_length(v.len(), &self));
+                }
+                let mut bytes = [0u8; $len];
+                bytes.copy_from_slice(v);
+                Ok($ty::from(bytes))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$ty, D::Error> {
+                if deserializer.is_human_readable() {
+                    let s = String::deserialize(deserializer)?;
+                    s.parse().map_err(de::Error::custom)
+                } else {
+                    deserializer.deserialize_bytes($visitor)
+                }
+            }
+        }
+    }
+}
+
+impl_serde!(Hash32, Hash32Visitor, 4);
+impl_serde!(Hash64, Hash64Visitor, 8);
+impl_serde!(Hash128, Hash128Visitor, 16);
@@ -13,6 +13,13 @@ pub const XXH_ERROR: XXH_errorcode = 1;
 
 pub enum XXH32_state_t {}
 pub enum XXH64_state_t {}
+pub enum XXH3_state_t {}
+
+/// Minimum size, in bytes, accepted for a custom XXH3 secret.
+pub const XXH3_SECRET_SIZE_MIN: usize = 136;
+
+/// Size, in bytes, of the secret produced by `XXH3_generateSecret`.
+pub const XXH3_SECRET_DEFAULT_SIZE: usize = 192;
 
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -36,6 +43,27 @@ pub struct XXH64_canonical_t {
     pub digest: [c_uchar; 8],
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct XXH128_hash_t {
+    pub low64: XXH64_hash_t,
+    pub high64: XXH64_hash_t,
+}
+
+#[repr(C)]
+pub struct XXH128_canonical_t {
+    pub digest: [c_uchar; 16],
+}
+
+// `XXH3_state_t` is documented as 576 bytes, aligned to 64 bytes, so we
+// mirror that layout the same way `XXH32_stateBody_t`/`XXH64_stateBody_t`
+// mirror their C counterparts.
+#[repr(C, align(64))]
+#[derive(Copy, Clone)]
+pub struct XXH3_stateBody_t {
+    pub ll: [c_longlong; 72],
+}
+
 extern {
     #[link_name = "__rust_xxhash_sys_XXH32"]
     pub fn XXH32(input: *const c_void,
@@ -86,4 +114,79 @@ extern {
                                    -> XXH32_hash_t;
     pub fn XXH64_hashFromCanonical(src: *const XXH64_canonical_t)
                                    -> XXH64_hash_t;
+
+    #[link_name = "__rust_xxhash_sys_XXH3_64bits"]
+    pub fn XXH3_64bits(input: *const c_void, length: size_t) -> XXH64_hash_t;
+    #[link_name = "__rust_xxhash_sys_XXH3_64bits_withSeed"]
+    pub fn XXH3_64bits_withSeed(input: *const c_void,
+                                length: size_t,
+                                seed: XXH64_hash_t) -> XXH64_hash_t;
+    #[link_name = "__rust_xxhash_sys_XXH3_64bits_withSecret"]
+    pub fn XXH3_64bits_withSecret(input: *const c_void,
+                                  length: size_t,
+                                  secret: *const c_void,
+                                  secretSize: size_t) -> XXH64_hash_t;
+
+    #[link_name = "__rust_xxhash_sys_XXH3_createState"]
+    pub fn XXH3_createState() -> *mut XXH3_state_t;
+    #[link_name = "__rust_xxhash_sys_XXH3_freeState"]
+    pub fn XXH3_freeState(ptr: *mut XXH3_state_t) -> XXH_errorcode;
+
+    #[link_name = "__rust_xxhash_sys_XXH3_64bits_reset"]
+    pub fn XXH3_64bits_reset(statePtr: *mut XXH3_state_t) -> XXH_errorcode;
+    #[link_name = "__rust_xxhash_sys_XXH3_64bits_reset_withSeed"]
+    pub fn XXH3_64bits_reset_withSeed(statePtr: *mut XXH3_state_t,
+                                      seed: XXH64_hash_t) -> XXH_errorcode;
+    #[link_name = "__rust_xxhash_sys_XXH3_64bits_reset_withSecret"]
+    pub fn XXH3_64bits_reset_withSecret(statePtr: *mut XXH3_state_t,
+                                        secret: *const c_void,
+                                        secretSize: size_t) -> XXH_errorcode;
+    #[link_name = "__rust_xxhash_sys_XXH3_64bits_update"]
+    pub fn XXH3_64bits_update(statePtr: *mut XXH3_state_t,
+                              input: *const c_void,
+                              length: size_t) -> XXH_errorcode;
+    #[link_name = "__rust_xxhash_sys_XXH3_64bits_digest"]
+    pub fn XXH3_64bits_digest(statePtr: *const XXH3_state_t) -> XXH64_hash_t;
+
+    #[link_name = "__rust_xxhash_sys_XXH3_generateSecret"]
+    pub fn XXH3_generateSecret(secretBuffer: *mut c_void,
+                               secretSize: size_t,
+                               customSeed: *const c_void,
+                               customSeedSize: size_t) -> XXH_errorcode;
+
+    #[link_name = "__rust_xxhash_sys_XXH3_128bits"]
+    pub fn XXH3_128bits(input: *const c_void, length: size_t) -> XXH128_hash_t;
+    #[link_name = "__rust_xxhash_sys_XXH3_128bits_withSeed"]
+    pub fn XXH3_128bits_withSeed(input: *const c_void,
+                                 length: size_t,
+                                 seed: XXH64_hash_t) -> XXH128_hash_t;
+    #[link_name = "__rust_xxhash_sys_XXH3_128bits_withSecret"]
+    pub fn XXH3_128bits_withSecret(input: *const c_void,
+                                   length: size_t,
+                                   secret: *const c_void,
+                                   secretSize: size_t) -> XXH128_hash_t;
+
+    #[link_name = "__rust_xxhash_sys_XXH3_128bits_reset"]
+    pub fn XXH3_128bits_reset(statePtr: *mut XXH3_state_t) -> XXH_errorcode;
+    #[link_name = "__rust_xxhash_sys_XXH3_128bits_reset_withSeed"]
+    pub fn XXH3_128bits_reset_withSeed(statePtr: *mut XXH3_state_t,
+                                       seed: XXH64_hash_t) -> XXH_errorcode;
+    #[link_name = "__rust_xxhash_sys_XXH3_128bits_reset_withSecret"]
+    pub fn XXH3_128bits_reset_withSecret(statePtr: *mut XXH3_state_t,
+                                         secret: *const c_void,
+                                         secretSize: size_t) -> XXH_errorcode;
+    #[link_name = "__rust_xxhash_sys_XXH3_128bits_update"]
+    pub fn XXH3_128bits_update(statePtr: *mut XXH3_state_t,
+                               input: *const c_void,
+                               length: size_t) -> XXH_errorcode;
+    #[link_name = "__rust_xxhash_sys_XXH3_128bits_digest"]
+    pub fn XXH3_128bits_digest(statePtr: *const XXH3_state_t) -> XXH128_hash_t;
+
+    // apis that aren't namespaced?!
+
+    pub fn XXH128_canonicalFromHash(dst: *mut XXH128_canonical_t,
+                                    hash: XXH128_hash_t);
+    pub fn XXH128_hashFromCanonical(src: *const XXH128_canonical_t)
+                                    -> XXH128_hash_t;
+    pub fn XXH128_isEqual(h1: XXH128_hash_t, h2: XXH128_hash_t) -> c_int;
 }